@@ -0,0 +1,202 @@
+//! Pluggable body formats for [`TypedResponse`](crate::TypedResponse).
+//!
+//! [`BodyFormat`] abstracts over the wire format used to deserialize a response
+//! body. [`Json`] is always available; the other formats are gated behind their
+//! respective cargo features so callers only pay for the codecs they use.
+
+use std::fmt;
+
+use serde::de;
+
+/// Deserializes a response body of a particular wire format into `T`.
+///
+pub trait BodyFormat {
+    /// Deserializes `body` into `T`, or a [`FormatError`] describing why it failed.
+    ///
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError>;
+}
+
+/// A deserialization failure from any of the supported [`BodyFormat`]s.
+///
+#[derive(Debug)]
+pub enum FormatError {
+    Json(serde_json::Error),
+    #[cfg(feature = "cbor")]
+    Cbor(ciborium::de::Error<std::io::Error>),
+    #[cfg(feature = "msgpack")]
+    MsgPack(rmp_serde::decode::Error),
+    #[cfg(feature = "urlencoded")]
+    UrlEncoded(serde_urlencoded::de::Error),
+    #[cfg(feature = "xml")]
+    Xml(quick_xml::DeError),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "JSON: {e}"),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(e) => write!(f, "CBOR: {e}"),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(e) => write!(f, "MessagePack: {e}"),
+            #[cfg(feature = "urlencoded")]
+            Self::UrlEncoded(e) => write!(f, "form-urlencoded: {e}"),
+            #[cfg(feature = "xml")]
+            Self::Xml(e) => write!(f, "XML: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            #[cfg(feature = "cbor")]
+            Self::Cbor(e) => Some(e),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(e) => Some(e),
+            #[cfg(feature = "urlencoded")]
+            Self::UrlEncoded(e) => Some(e),
+            #[cfg(feature = "xml")]
+            Self::Xml(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for FormatError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+/// `application/json`, via `serde_json`. The default format when none is specified.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Json;
+
+impl BodyFormat for Json {
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError> {
+        serde_json::from_slice(body).map_err(FormatError::Json)
+    }
+}
+
+/// `application/cbor`, via `ciborium`.
+///
+#[cfg(feature = "cbor")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl BodyFormat for Cbor {
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError> {
+        ciborium::de::from_reader(body.as_ref()).map_err(FormatError::Cbor)
+    }
+}
+
+/// `application/msgpack`, via `rmp-serde`.
+///
+#[cfg(feature = "msgpack")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MsgPack;
+
+#[cfg(feature = "msgpack")]
+impl BodyFormat for MsgPack {
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError> {
+        rmp_serde::from_slice(body).map_err(FormatError::MsgPack)
+    }
+}
+
+/// `application/x-www-form-urlencoded`, via `serde_urlencoded`.
+///
+#[cfg(feature = "urlencoded")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UrlEncoded;
+
+#[cfg(feature = "urlencoded")]
+impl BodyFormat for UrlEncoded {
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError> {
+        serde_urlencoded::from_bytes(body).map_err(FormatError::UrlEncoded)
+    }
+}
+
+/// `text/xml` / `application/xml`, via `quick-xml`.
+///
+#[cfg(feature = "xml")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Xml;
+
+#[cfg(feature = "xml")]
+impl BodyFormat for Xml {
+    fn deserialize<T: de::DeserializeOwned>(&self, body: &bytes::Bytes) -> Result<T, FormatError> {
+        quick_xml::de::from_reader(body.as_ref()).map_err(FormatError::Xml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        name: String,
+        count: u32,
+    }
+
+    fn payload() -> Payload {
+        Payload {
+            name: "widget".to_owned(),
+            count: 3,
+        }
+    }
+
+    #[test]
+    fn json_round_trips() {
+        let bytes = bytes::Bytes::from(serde_json::to_vec(&payload()).unwrap());
+        let decoded: Payload = Json.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, payload());
+    }
+
+    #[test]
+    fn json_error_is_wrapped_and_displays_its_message() {
+        let bytes = bytes::Bytes::from_static(b"not json");
+        let err = Json.deserialize::<Payload>(&bytes).unwrap_err();
+        assert!(matches!(err, FormatError::Json(_)));
+        assert!(err.to_string().starts_with("JSON: "));
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn cbor_round_trips() {
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&payload(), &mut buf).unwrap();
+        let bytes = bytes::Bytes::from(buf);
+        let decoded: Payload = Cbor.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, payload());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn msgpack_round_trips() {
+        let bytes = bytes::Bytes::from(rmp_serde::to_vec(&payload()).unwrap());
+        let decoded: Payload = MsgPack.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, payload());
+    }
+
+    #[cfg(feature = "urlencoded")]
+    #[test]
+    fn urlencoded_round_trips() {
+        let bytes = bytes::Bytes::from(serde_urlencoded::to_string(payload()).unwrap());
+        let decoded: Payload = UrlEncoded.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, payload());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn xml_round_trips() {
+        let bytes =
+            bytes::Bytes::from_static(b"<Payload><name>widget</name><count>3</count></Payload>");
+        let decoded: Payload = Xml.deserialize(&bytes).unwrap();
+        assert_eq!(decoded, payload());
+    }
+}
@@ -21,19 +21,58 @@
 #![deny(warnings)]
 
 use std::borrow::Cow;
+use std::fmt;
 use std::marker::PhantomData;
 
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Url};
 use serde::de;
 use serde_json as json;
 
 #[cfg(feature = "blocking")]
 pub mod blocking;
+pub mod format;
+
+pub use format::{BodyFormat, FormatError};
+
+/// How a response should be treated by [`TypedResponse::try_from_response_with`],
+/// decided from its `StatusCode` and `HeaderMap` before the body is read.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Classification {
+    /// Treat the response as a success, deserializing the body as `T`.
+    Ok,
+    /// Treat the response as a failure, deserializing the body as `E`.
+    Err,
+    /// Call `error_for_status_ref()` before reading the body, turning the
+    /// response into a `reqwest::Error` if its status is a client or server
+    /// error. If the status is not actually an error, falls back to
+    /// `StatusCode::is_success()`.
+    BailEarly,
+}
+
+/// The default [`Classification`]: server errors bail early, everything else is
+/// classified by [`StatusCode::is_success`].
+///
+fn default_classify(status: &StatusCode, _headers: &HeaderMap) -> Classification {
+    if status.is_server_error() {
+        Classification::BailEarly
+    } else if status.is_success() {
+        Classification::Ok
+    } else {
+        Classification::Err
+    }
+}
 
 /// Holds raw response body, while remembering desired shape of the success (`T`)
 /// and failure (`E`) variants.
 ///
 #[derive(Clone, Debug)]
 pub struct TypedResponse<T, E> {
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Url,
+    content_length: Option<u64>,
     body: bytes::Bytes,
     result: Result<PhantomData<T>, PhantomData<E>>,
 }
@@ -43,22 +82,81 @@ where
     T: de::DeserializeOwned,
     E: de::DeserializeOwned + From<json::Error>,
 {
-    /// Converts `reqwest::Response` into `TypedResponse<T, E>`
+    /// Converts `reqwest::Response` into `TypedResponse<T, E>`, classifying the
+    /// response by its HTTP status: a server error is returned as a
+    /// `reqwest::Error` before the body is read, anything else is a success or
+    /// a failure as [`StatusCode::is_success`] decides.
     ///
     pub async fn try_from_response(response: reqwest::Response) -> reqwest::Result<Self> {
-        let result = match response.status().is_success() {
-            false => Err(PhantomData),
-            true => Ok(PhantomData),
-        };
+        Self::try_from_response_with(response, default_classify).await
+    }
+
+    /// Converts `reqwest::Response` into `TypedResponse<T, E>`, using `classifier`
+    /// to decide whether the response should be treated as a success (`T`), a
+    /// failure (`E`), or should bail out early with a `reqwest::Error` before the
+    /// body is even read (see [`Classification`]).
+    ///
+    pub async fn try_from_response_with<F>(
+        response: reqwest::Response,
+        classifier: F,
+    ) -> reqwest::Result<Self>
+    where
+        F: Fn(&StatusCode, &HeaderMap) -> Classification,
+    {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let url = response.url().clone();
+        let content_length = response.content_length();
+
+        let classification = classifier(&status, &headers);
 
-        // Bail early on server error
-        if response.status().is_server_error() {
+        if let Classification::BailEarly = classification {
             response.error_for_status_ref()?;
         }
 
+        let result = match classification {
+            Classification::Ok => Ok(PhantomData),
+            Classification::Err => Err(PhantomData),
+            Classification::BailEarly => match status.is_success() {
+                true => Ok(PhantomData),
+                false => Err(PhantomData),
+            },
+        };
+
         let body = response.bytes().await?;
 
-        Ok(Self { body, result })
+        Ok(Self {
+            status,
+            headers,
+            url,
+            content_length,
+            body,
+            result,
+        })
+    }
+
+    /// The HTTP status code of the original response
+    ///
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The HTTP headers of the original response
+    ///
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The final `Url` of the original response, after following any redirects
+    ///
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The `Content-Length` of the original response, if known
+    ///
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
     }
 
     /// Access the raw HTTP response as bytes
@@ -69,8 +167,53 @@ where
 
     /// Access the raw HTTP response body as text
     ///
+    /// The encoding is determined from the `charset` parameter of the response's
+    /// `Content-Type` header, defaulting to UTF-8 when absent or unrecognised. A
+    /// UTF-8/UTF-16 byte-order-mark in the body takes precedence over the declared
+    /// charset. Malformed sequences are replaced with the Unicode replacement
+    /// character.
+    ///
     pub fn text(&self) -> Cow<'_, str> {
-        String::from_utf8_lossy(&self.body)
+        self.decode_text(None)
+    }
+
+    /// Access the raw HTTP response body as text, decoded with `encoding_label`,
+    /// ignoring whatever charset the `Content-Type` header declares. Useful when
+    /// the caller knows better than a mislabeled or absent header. A UTF-8/UTF-16
+    /// byte-order-mark in the body still takes precedence, same as `text()`.
+    ///
+    pub fn text_with_charset(&self, encoding_label: &str) -> Cow<'_, str> {
+        self.decode_text(Some(encoding_label))
+    }
+
+    /// Shared implementation of `text()` / `text_with_charset()`. `forced_encoding`
+    /// of `None` means "no explicit choice", falling back to the `Content-Type`
+    /// header's `charset` and then UTF-8.
+    ///
+    fn decode_text(&self, forced_encoding: Option<&str>) -> Cow<'_, str> {
+        let encoding_name = match forced_encoding {
+            Some(label) => Cow::Borrowed(label),
+            None => {
+                let content_type = self
+                    .headers
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse::<mime::Mime>().ok());
+
+                content_type
+                    .as_ref()
+                    .and_then(|mime| mime.get_param(mime::CHARSET))
+                    .map_or(Cow::Borrowed("utf-8"), |charset| {
+                        Cow::Owned(charset.as_str().to_owned())
+                    })
+            }
+        };
+
+        let encoding = encoding_rs::Encoding::for_label(encoding_name.as_bytes())
+            .unwrap_or(encoding_rs::UTF_8);
+
+        let (text, _, _) = encoding.decode(&self.body);
+        text
     }
 
     /// Convert this response into `Result<serde_json::Value, serde_json::Value>`
@@ -97,12 +240,171 @@ where
     }
 }
 
+impl<T, E> TypedResponse<T, E>
+where
+    E: de::DeserializeOwned + From<json::Error>,
+{
+    /// Returns a `serde_json::Deserializer` borrowing from the stored body, for
+    /// callers that want to deserialize into types containing `&'de str` /
+    /// `&'de [u8]` without allocating owned copies of every field.
+    ///
+    pub fn deserializer(&self) -> json::Deserializer<json::de::SliceRead<'_>> {
+        json::Deserializer::from_slice(&self.body)
+    }
+
+    /// Deserialize the success body into `D`, borrowing from the stored `Bytes`
+    /// instead of requiring `DeserializeOwned`. `Ok`/`Err` are still chosen based
+    /// on the original HTTP status; a failure response is still deserialized as
+    /// the owned `E`.
+    ///
+    pub fn deserialize_borrowed<'de, D: de::Deserialize<'de>>(&'de self) -> Result<D, E> {
+        match self.result {
+            Ok(_) => Ok(D::deserialize(&mut self.deserializer())?),
+            Err(_) => Err(json::from_slice(&self.body)?),
+        }
+    }
+}
+
+impl<T, E> TypedResponse<T, E>
+where
+    T: de::DeserializeOwned,
+    E: de::DeserializeOwned + From<FormatError>,
+{
+    /// Convert this response into `Result<T, E>` using `format` to deserialize the
+    /// body instead of assuming JSON. `Ok` and `Err` variants are still chosen
+    /// based on the original HTTP status.
+    ///
+    pub fn into_result_with<F: BodyFormat>(self, format: F) -> Result<T, E> {
+        match self.result {
+            Ok(_) => Ok(format.deserialize(&self.body)?),
+            Err(_) => Err(format.deserialize(&self.body)?),
+        }
+    }
+
+    /// Convert this response into `Result<T, E>`, picking the [`BodyFormat`] from
+    /// the stored `Content-Type` header. Falls back to JSON when the header is
+    /// missing, unrecognised, or names a format whose cargo feature isn't enabled.
+    ///
+    pub fn into_result_auto(self) -> Result<T, E> {
+        let content_type = self
+            .headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<mime::Mime>().ok());
+
+        match content_type
+            .as_ref()
+            .map(|m| (m.type_().as_str(), m.subtype().as_str()))
+        {
+            #[cfg(feature = "cbor")]
+            Some(("application", "cbor")) => self.into_result_with(format::Cbor),
+            #[cfg(feature = "msgpack")]
+            Some(("application", "msgpack" | "x-msgpack")) => {
+                self.into_result_with(format::MsgPack)
+            }
+            #[cfg(feature = "urlencoded")]
+            Some(("application", "x-www-form-urlencoded")) => {
+                self.into_result_with(format::UrlEncoded)
+            }
+            #[cfg(feature = "xml")]
+            Some(("text" | "application", "xml")) => self.into_result_with(format::Xml),
+            _ => self.into_result_with(format::Json),
+        }
+    }
+}
+
+impl<T, E> TypedResponse<T, E>
+where
+    T: de::DeserializeOwned,
+    E: de::DeserializeOwned,
+{
+    /// Convert this response into `Result<T, E>`, same as [`into_result`](Self::into_result),
+    /// but surfaces a malformed body as a rich [`DeserializeError`] instead of
+    /// folding it into `E`. The JSON path to the offending value (e.g.
+    /// `data.items[3].id`) is captured via `serde_path_to_error`, alongside serde's
+    /// own message and a snippet of the surrounding body.
+    ///
+    pub fn try_into_result(self) -> Result<Result<T, E>, DeserializeError> {
+        match self.result {
+            Ok(_) => Ok(Ok(self.deserialize_json()?)),
+            Err(_) => Ok(Err(self.deserialize_json()?)),
+        }
+    }
+
+    fn deserialize_json<D: de::DeserializeOwned>(&self) -> Result<D, DeserializeError> {
+        let deserializer = &mut json::Deserializer::from_slice(&self.body);
+        serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| DeserializeError::new(e, &self.body))
+    }
+}
+
+/// A rich deserialization failure produced by [`TypedResponse::try_into_result`].
+///
+/// Unlike the plain [`serde_json::Error`] surfaced elsewhere in this crate, this
+/// captures the dotted path to the offending value so failures on large bodies
+/// are actionable without reaching for a debugger.
+///
+#[derive(Clone, Debug)]
+pub struct DeserializeError {
+    /// Dotted path to the value that failed to deserialize, e.g. `data.items[3].id`.
+    pub path: String,
+    /// serde's own error message, including its line/column.
+    pub message: String,
+    /// A short window of the body surrounding the failure, for context.
+    pub snippet: String,
+}
+
+impl DeserializeError {
+    fn new(error: serde_path_to_error::Error<json::Error>, body: &bytes::Bytes) -> Self {
+        let path = error.path().to_string();
+        let inner = error.into_inner();
+        let message = inner.to_string();
+        let snippet = Self::snippet(body, inner.line(), inner.column());
+
+        Self {
+            path,
+            message,
+            snippet,
+        }
+    }
+
+    fn snippet(body: &[u8], line: usize, column: usize) -> String {
+        const RADIUS: usize = 40;
+
+        let text = String::from_utf8_lossy(body);
+        let Some(line_str) = text.lines().nth(line.saturating_sub(1)) else {
+            return String::new();
+        };
+
+        let start = column.saturating_sub(RADIUS);
+        let end = (column + RADIUS).min(line_str.len());
+        line_str.get(start..end).unwrap_or(line_str).to_string()
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at `{}`: {}", self.message, self.path, self.snippet)
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
 #[async_trait::async_trait]
 pub trait ResponseExt: Sized {
     async fn try_from_response<T, E>(self) -> reqwest::Result<TypedResponse<T, E>>
     where
         T: de::DeserializeOwned + Send,
         E: de::DeserializeOwned + From<json::Error> + Send;
+
+    async fn try_from_response_with<T, E, F>(
+        self,
+        classifier: F,
+    ) -> reqwest::Result<TypedResponse<T, E>>
+    where
+        T: de::DeserializeOwned + Send,
+        E: de::DeserializeOwned + From<json::Error> + Send,
+        F: Fn(&StatusCode, &HeaderMap) -> Classification + Send;
 }
 
 #[async_trait::async_trait]
@@ -114,4 +416,352 @@ impl ResponseExt for reqwest::Response {
     {
         TypedResponse::try_from_response(self).await
     }
+
+    async fn try_from_response_with<T, E, F>(
+        self,
+        classifier: F,
+    ) -> reqwest::Result<TypedResponse<T, E>>
+    where
+        T: de::DeserializeOwned + Send,
+        E: de::DeserializeOwned + From<json::Error> + Send,
+        F: Fn(&StatusCode, &HeaderMap) -> Classification + Send,
+    {
+        TypedResponse::try_from_response_with(self, classifier).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde::Deserialize)]
+    struct TestError {
+        #[allow(dead_code)]
+        message: String,
+    }
+
+    impl From<json::Error> for TestError {
+        fn from(e: json::Error) -> Self {
+            Self {
+                message: e.to_string(),
+            }
+        }
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Borrowed<'a> {
+        #[serde(borrow)]
+        name: &'a str,
+    }
+
+    impl From<FormatError> for TestError {
+        fn from(e: FormatError) -> Self {
+            Self {
+                message: e.to_string(),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Payload {
+        name: String,
+        count: u32,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Item {
+        id: u32,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Data {
+        items: Vec<Item>,
+    }
+
+    fn response_with_body(body: &str) -> TypedResponse<(), TestError> {
+        response_with_bytes(bytes::Bytes::from(body.to_owned()), None)
+    }
+
+    fn response_with_bytes(
+        body: bytes::Bytes,
+        content_type: Option<&str>,
+    ) -> TypedResponse<(), TestError> {
+        typed_response(body, content_type)
+    }
+
+    fn typed_response<T, E>(body: bytes::Bytes, content_type: Option<&str>) -> TypedResponse<T, E> {
+        let mut headers = HeaderMap::new();
+        if let Some(content_type) = content_type {
+            headers.insert(reqwest::header::CONTENT_TYPE, content_type.parse().unwrap());
+        }
+
+        TypedResponse {
+            status: StatusCode::OK,
+            headers,
+            url: Url::parse("http://example.test").unwrap(),
+            content_length: None,
+            body,
+            result: Ok(PhantomData),
+        }
+    }
+
+    /// Binds a one-shot TCP listener that writes `raw_response` to the first
+    /// connection it accepts, and returns its address. Lets tests exercise a
+    /// real `reqwest::Response` without a network round-trip to an external host.
+    ///
+    fn spawn_one_shot_server(raw_response: String) -> std::net::SocketAddr {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            use std::io::{Read, Write};
+
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(raw_response.as_bytes());
+                let _ = stream.flush();
+            }
+        });
+
+        addr
+    }
+
+    fn http_response(status_line: &str, headers: &[(&str, &str)], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {status_line}\r\n");
+        for (name, value) in headers {
+            response += &format!("{name}: {value}\r\n");
+        }
+        response += &format!("Content-Length: {}\r\n\r\n{}", body.len(), body);
+        response
+    }
+
+    #[test]
+    fn deserialize_borrowed_zero_copies_string_fields() {
+        let response = response_with_body(r#"{"name":"hello"}"#);
+        let borrowed: Borrowed<'_> = response.deserialize_borrowed().unwrap();
+        assert_eq!(borrowed.name, "hello");
+    }
+
+    #[test]
+    fn text_decodes_using_content_type_charset() {
+        // "café" in windows-1252: the trailing 0xE9 is not valid standalone UTF-8.
+        let body = bytes::Bytes::from_static(b"caf\xE9");
+        let response = response_with_bytes(body, Some("text/plain; charset=windows-1252"));
+
+        assert_eq!(response.text(), "café");
+    }
+
+    #[test]
+    fn text_with_charset_overrides_a_mismatched_content_type_header() {
+        // Header claims UTF-8, but the body is actually windows-1252.
+        let body = bytes::Bytes::from_static(b"caf\xE9");
+        let response = response_with_bytes(body, Some("text/plain; charset=utf-8"));
+
+        // Trusting the (wrong) header mangles the body.
+        assert!(response.text().contains('\u{FFFD}'));
+
+        // The caller's explicit override wins.
+        assert_eq!(response.text_with_charset("windows-1252"), "café");
+    }
+
+    #[test]
+    fn try_into_result_captures_the_dotted_path_to_a_bad_field() {
+        let body = bytes::Bytes::from_static(br#"{"items":[{"id":1},{"id":"not-a-number"}]}"#);
+        let response: TypedResponse<Data, TestError> = typed_response(body, None);
+
+        let err = response.try_into_result().unwrap_err();
+        assert_eq!(err.path, "items[1].id");
+        assert!(err.message.contains("invalid type"));
+    }
+
+    #[test]
+    fn try_into_result_snippet_does_not_panic_near_multi_byte_utf8() {
+        // The "café" sits a few bytes before the start of the snippet's
+        // window, so the byte-offset windowing runs over a non-ASCII line and
+        // must still produce a snippet without panicking.
+        let body = bytes::Bytes::from(
+            r#"{"items":[{"id":"café not a number, padded out past the radius"}]}"#.to_string(),
+        );
+        let response: TypedResponse<Data, TestError> = typed_response(body, None);
+
+        let err = response.try_into_result().unwrap_err();
+        assert!(!err.snippet.is_empty());
+        assert!(err.to_string().contains(&err.path));
+    }
+
+    #[test]
+    fn try_into_result_succeeds_for_a_well_formed_body() {
+        let body = bytes::Bytes::from_static(br#"{"items":[{"id":1},{"id":2}]}"#);
+        let response: TypedResponse<Data, TestError> = typed_response(body, None);
+
+        let data = response.try_into_result().unwrap().unwrap();
+        assert_eq!(data.items.len(), 2);
+        assert_eq!(data.items[1].id, 2);
+    }
+
+    fn payload_value() -> Payload {
+        Payload {
+            name: "widget".to_owned(),
+            count: 3,
+        }
+    }
+
+    #[test]
+    fn into_result_auto_falls_back_to_json_without_a_content_type() {
+        let body = bytes::Bytes::from(serde_json::to_vec(&payload_value()).unwrap());
+        let response: TypedResponse<Payload, TestError> = typed_response(body, None);
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[test]
+    fn into_result_auto_routes_explicit_json_content_type() {
+        let body = bytes::Bytes::from(serde_json::to_vec(&payload_value()).unwrap());
+        let response: TypedResponse<Payload, TestError> =
+            typed_response(body, Some("application/json"));
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn into_result_auto_routes_cbor_content_type() {
+        let mut buf = Vec::new();
+        ciborium::ser::into_writer(&payload_value(), &mut buf).unwrap();
+        let response: TypedResponse<Payload, TestError> =
+            typed_response(bytes::Bytes::from(buf), Some("application/cbor"));
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn into_result_auto_routes_msgpack_content_type() {
+        let body = bytes::Bytes::from(rmp_serde::to_vec(&payload_value()).unwrap());
+        let response: TypedResponse<Payload, TestError> =
+            typed_response(body, Some("application/x-msgpack"));
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[cfg(feature = "urlencoded")]
+    #[test]
+    fn into_result_auto_routes_urlencoded_content_type() {
+        let body = bytes::Bytes::from(serde_urlencoded::to_string(payload_value()).unwrap());
+        let response: TypedResponse<Payload, TestError> =
+            typed_response(body, Some("application/x-www-form-urlencoded"));
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn into_result_auto_routes_xml_content_type() {
+        let body =
+            bytes::Bytes::from_static(b"<Payload><name>widget</name><count>3</count></Payload>");
+        let response: TypedResponse<Payload, TestError> =
+            typed_response(body, Some("application/xml"));
+
+        assert_eq!(response.into_result_auto().unwrap(), payload_value());
+    }
+
+    #[tokio::test]
+    async fn accessors_reflect_the_original_response() {
+        let addr = spawn_one_shot_server(http_response(
+            "200 OK",
+            &[("Content-Type", "application/json")],
+            r#"{"ok":true}"#,
+        ));
+
+        let response = reqwest::get(format!("http://{addr}")).await.unwrap();
+        let typed: TypedResponse<json::Value, TestError> =
+            TypedResponse::try_from_response(response).await.unwrap();
+
+        assert_eq!(typed.status(), StatusCode::OK);
+        assert_eq!(
+            typed.headers().get(reqwest::header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(typed.url().host_str(), Some(addr.ip().to_string().as_str()));
+        assert_eq!(typed.content_length(), Some(11));
+    }
+
+    #[tokio::test]
+    async fn default_classification_matches_pre_refactor_behavior() {
+        let ok_addr = spawn_one_shot_server(http_response("200 OK", &[], r#"{"ok":true}"#));
+        let ok_response = reqwest::get(format!("http://{ok_addr}")).await.unwrap();
+        let ok_typed: TypedResponse<json::Value, TestError> =
+            TypedResponse::try_from_response(ok_response).await.unwrap();
+        assert!(ok_typed.into_result().is_ok());
+
+        let not_found_addr =
+            spawn_one_shot_server(http_response("404 Not Found", &[], r#"{"ok":false}"#));
+        let not_found_response = reqwest::get(format!("http://{not_found_addr}"))
+            .await
+            .unwrap();
+        let not_found_typed: TypedResponse<json::Value, TestError> =
+            TypedResponse::try_from_response(not_found_response)
+                .await
+                .unwrap();
+        assert!(not_found_typed.into_result().is_err());
+
+        let server_error_addr =
+            spawn_one_shot_server(http_response("500 Internal Server Error", &[], "oops"));
+        let server_error_response = reqwest::get(format!("http://{server_error_addr}"))
+            .await
+            .unwrap();
+        let bail_early =
+            TypedResponse::<json::Value, TestError>::try_from_response(server_error_response).await;
+        assert!(bail_early.is_err());
+    }
+
+    #[tokio::test]
+    async fn custom_classifier_treats_a_200_body_as_a_business_error() {
+        let addr = spawn_one_shot_server(http_response(
+            "200 OK",
+            &[],
+            r#"{"message":"business rule violated"}"#,
+        ));
+        let response = reqwest::get(format!("http://{addr}")).await.unwrap();
+
+        let classify_business_errors = |status: &StatusCode, headers: &HeaderMap| {
+            if status.is_success() {
+                Classification::Err
+            } else {
+                default_classify(status, headers)
+            }
+        };
+
+        let typed: TypedResponse<json::Value, TestError> =
+            TypedResponse::try_from_response_with(response, classify_business_errors)
+                .await
+                .unwrap();
+
+        assert!(typed.into_result().is_err());
+    }
+
+    #[tokio::test]
+    async fn custom_classifier_deserializes_a_structured_server_error_instead_of_bailing() {
+        let addr = spawn_one_shot_server(http_response(
+            "500 Internal Server Error",
+            &[],
+            r#"{"message":"boom"}"#,
+        ));
+        let response = reqwest::get(format!("http://{addr}")).await.unwrap();
+
+        let never_bail =
+            |status: &StatusCode, headers: &HeaderMap| match default_classify(status, headers) {
+                Classification::BailEarly => Classification::Err,
+                other => other,
+            };
+
+        let typed: TypedResponse<json::Value, TestError> =
+            TypedResponse::try_from_response_with(response, never_bail)
+                .await
+                .unwrap();
+
+        let err = typed.into_result().unwrap_err();
+        assert_eq!(err.message, "boom");
+    }
 }